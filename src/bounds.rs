@@ -0,0 +1,393 @@
+//! Internal `(Bound<T>, Bound<T>)` representation shared by every `RangeExt` operation.
+//!
+//! Everything in this module is crate-private plumbing: it lets `concat`, `cut`, `compose`
+//! and friends be written once against the normalized representation that
+//! `std::ops::RangeBounds` already exposes, instead of once per concrete range type.
+
+use std::ops::{Add, Bound, Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+
+/// Consume a concrete range type into its normalized `(start, end)` bounds.
+///
+/// `Item` (rather than a generic parameter on the trait itself) keeps `Self` the only thing
+/// impls are chosen by, so a blanket impl over `Lhs: IntoBounds` doesn't leave its element
+/// type unconstrained.
+pub trait IntoBounds {
+    type Item;
+
+    fn into_bounds(self) -> (Bound<Self::Item>, Bound<Self::Item>);
+}
+
+impl<T> IntoBounds for Range<T> {
+    type Item = T;
+
+    fn into_bounds(self) -> (Bound<T>, Bound<T>) {
+        (Bound::Included(self.start), Bound::Excluded(self.end))
+    }
+}
+
+impl<T> IntoBounds for RangeFrom<T> {
+    type Item = T;
+
+    fn into_bounds(self) -> (Bound<T>, Bound<T>) {
+        (Bound::Included(self.start), Bound::Unbounded)
+    }
+}
+
+impl<T> IntoBounds for RangeTo<T> {
+    type Item = T;
+
+    fn into_bounds(self) -> (Bound<T>, Bound<T>) {
+        (Bound::Unbounded, Bound::Excluded(self.end))
+    }
+}
+
+impl<T> IntoBounds for RangeInclusive<T> {
+    type Item = T;
+
+    fn into_bounds(self) -> (Bound<T>, Bound<T>) {
+        let (start, end) = self.into_inner();
+        (Bound::Included(start), Bound::Included(end))
+    }
+}
+
+impl<T> IntoBounds for RangeToInclusive<T> {
+    type Item = T;
+
+    fn into_bounds(self) -> (Bound<T>, Bound<T>) {
+        (Bound::Unbounded, Bound::Included(self.end))
+    }
+}
+
+/// Borrow a concrete range type's bounds without consuming it.
+///
+/// `std::ops::RangeBounds<T>` already does this, but takes `T` as a trait parameter rather
+/// than an associated type, which would leave a blanket impl's `T` unconstrained (E0207).
+/// `Item` sidesteps that the same way `IntoBounds` does.
+pub trait BorrowedBounds {
+    type Item;
+
+    fn start_bound(&self) -> Bound<&Self::Item>;
+    fn end_bound(&self) -> Bound<&Self::Item>;
+}
+
+macro_rules! impl_borrowed_bounds {
+    ($($t:ident),* $(,)?) => {
+        $(
+            impl<T> BorrowedBounds for $t<T> {
+                type Item = T;
+
+                fn start_bound(&self) -> Bound<&T> {
+                    std::ops::RangeBounds::start_bound(self)
+                }
+
+                fn end_bound(&self) -> Bound<&T> {
+                    std::ops::RangeBounds::end_bound(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_borrowed_bounds!(Range, RangeFrom, RangeTo, RangeInclusive, RangeToInclusive);
+
+/// Rebuild a concrete range type from normalized bounds.
+///
+/// Implementations assume the bounds were produced by the matching [`IntoBounds`] impl (or a
+/// [`Combine`] output of the matching kind), so the `unreachable!` arms can't actually trigger.
+pub trait FromBounds<T>: Sized {
+    fn from_bounds(bounds: (Bound<T>, Bound<T>)) -> Self;
+}
+
+impl<T> FromBounds<T> for Range<T> {
+    fn from_bounds(bounds: (Bound<T>, Bound<T>)) -> Self {
+        match bounds {
+            (Bound::Included(start), Bound::Excluded(end)) => start..end,
+            _ => unreachable!("Range always has an included start and excluded end"),
+        }
+    }
+}
+
+impl<T> FromBounds<T> for RangeFrom<T> {
+    fn from_bounds(bounds: (Bound<T>, Bound<T>)) -> Self {
+        match bounds {
+            (Bound::Included(start), Bound::Unbounded) => start..,
+            _ => unreachable!("RangeFrom always has an included start and unbounded end"),
+        }
+    }
+}
+
+impl<T> FromBounds<T> for RangeTo<T> {
+    fn from_bounds(bounds: (Bound<T>, Bound<T>)) -> Self {
+        match bounds {
+            (Bound::Unbounded, Bound::Excluded(end)) => ..end,
+            _ => unreachable!("RangeTo always has an unbounded start and excluded end"),
+        }
+    }
+}
+
+impl<T> FromBounds<T> for RangeInclusive<T> {
+    fn from_bounds(bounds: (Bound<T>, Bound<T>)) -> Self {
+        match bounds {
+            (Bound::Included(start), Bound::Included(end)) => start..=end,
+            _ => unreachable!("RangeInclusive always has an included start and included end"),
+        }
+    }
+}
+
+impl<T> FromBounds<T> for RangeToInclusive<T> {
+    fn from_bounds(bounds: (Bound<T>, Bound<T>)) -> Self {
+        match bounds {
+            (Bound::Unbounded, Bound::Included(end)) => ..=end,
+            _ => unreachable!("RangeToInclusive always has an unbounded start and included end"),
+        }
+    }
+}
+
+/// The shape of a range's start bound: either it's pinned to a value, or it reaches back
+/// unbounded (`..` / `..=b`).
+pub trait StartKind {
+    type Kind: StartKindMarker;
+}
+
+pub trait StartKindMarker {}
+
+pub struct IncludedStart;
+pub struct UnboundedStart;
+
+impl StartKindMarker for IncludedStart {}
+impl StartKindMarker for UnboundedStart {}
+
+/// The shape of a range's end bound.
+pub trait EndKind {
+    type Kind: EndKindMarker;
+}
+
+pub trait EndKindMarker {}
+
+pub struct ExcludedEnd;
+pub struct IncludedEnd;
+pub struct UnboundedEnd;
+
+impl EndKindMarker for ExcludedEnd {}
+impl EndKindMarker for IncludedEnd {}
+impl EndKindMarker for UnboundedEnd {}
+
+impl<T> StartKind for Range<T> {
+    type Kind = IncludedStart;
+}
+impl<T> StartKind for RangeFrom<T> {
+    type Kind = IncludedStart;
+}
+impl<T> StartKind for RangeInclusive<T> {
+    type Kind = IncludedStart;
+}
+impl<T> StartKind for RangeTo<T> {
+    type Kind = UnboundedStart;
+}
+impl<T> StartKind for RangeToInclusive<T> {
+    type Kind = UnboundedStart;
+}
+
+impl<T> EndKind for Range<T> {
+    type Kind = ExcludedEnd;
+}
+impl<T> EndKind for RangeTo<T> {
+    type Kind = ExcludedEnd;
+}
+impl<T> EndKind for RangeInclusive<T> {
+    type Kind = IncludedEnd;
+}
+impl<T> EndKind for RangeToInclusive<T> {
+    type Kind = IncludedEnd;
+}
+impl<T> EndKind for RangeFrom<T> {
+    type Kind = UnboundedEnd;
+}
+
+/// Type-level lookup from `(element type, start kind, end kind)` back to the concrete range
+/// type that has that shape, for the five combinations `RangeExt` actually needs. (The sixth,
+/// `Unbounded`/`Unbounded`, would be `RangeFull`, which carries no element type and so can't
+/// participate in this scheme at all.)
+pub trait Combine<T, S: StartKindMarker, E: EndKindMarker> {
+    type Output;
+}
+
+pub struct Combiner;
+
+impl<T> Combine<T, IncludedStart, ExcludedEnd> for Combiner {
+    type Output = Range<T>;
+}
+impl<T> Combine<T, IncludedStart, IncludedEnd> for Combiner {
+    type Output = RangeInclusive<T>;
+}
+impl<T> Combine<T, IncludedStart, UnboundedEnd> for Combiner {
+    type Output = RangeFrom<T>;
+}
+impl<T> Combine<T, UnboundedStart, ExcludedEnd> for Combiner {
+    type Output = RangeTo<T>;
+}
+impl<T> Combine<T, UnboundedStart, IncludedEnd> for Combiner {
+    type Output = RangeToInclusive<T>;
+}
+
+/// Resolve the output range type for a start/end kind pair, generic over the element type.
+///
+/// `CombinedRange<IncludedStart, UnboundedEnd, u32>` is `RangeFrom<u32>`, etc.
+pub type CombinedRange<S, E, T> = <Combiner as Combine<T, S, E>>::Output;
+
+/// Whether `end` and `start` describe the same point, i.e. a range ending at `end` is
+/// immediately followed by a range starting at `start` with nothing in between and nothing
+/// overlapping.
+///
+/// Only the "meeting" shapes (`Excluded`/`Included` at the same value) count: a range can only
+/// be adjacent to what follows if it has a half-open end to begin with. An `Included` end is
+/// only adjacent to an `Excluded` start at the same value, which no std range type has, so a
+/// `RangeInclusive` or `RangeToInclusive` end is never adjacent to another range here, because knowing
+/// the next representable value after it would require a `T: Successor` bound this generic,
+/// `T: PartialEq`-only helper doesn't have. `(0..=3).concat(4..=9)` therefore reports
+/// [`crate::RangeError::NotAdjacent`] even though `3` and `4` are consecutive integers; use a
+/// half-open `self` (`0..4`) if you need `concat` to see that.
+pub fn bounds_are_adjacent<T: PartialEq>(end: Bound<&T>, start: Bound<&T>) -> bool {
+    match (end, start) {
+        (Bound::Excluded(a), Bound::Included(b)) => a == b,
+        (Bound::Included(a), Bound::Excluded(b)) => a == b,
+        _ => false,
+    }
+}
+
+pub fn bounds_eq<T: PartialEq>(a: Bound<&T>, b: Bound<&T>) -> bool {
+    match (a, b) {
+        (Bound::Included(a), Bound::Included(b)) => a == b,
+        (Bound::Excluded(a), Bound::Excluded(b)) => a == b,
+        (Bound::Unbounded, Bound::Unbounded) => true,
+        _ => false,
+    }
+}
+
+/// Whether a range ending at `end` lies entirely before a range starting at `start`, with no
+/// shared elements.
+///
+/// This doesn't distinguish a genuine gap from a touching boundary — `relation` only calls it
+/// after [`bounds_are_adjacent`] has already ruled out the half-open meeting point, so it's
+/// "before" in the sense of "not overlapping", including consecutive inclusive values it has no
+/// `T: Successor` bound to recognize as adjacent: `end_before_start(Included(&3), Included(&4))`
+/// is `true`, which is why `(0..=3).relation(&(4..=9))` reports `Disjoint` rather than
+/// `Adjacent`.
+pub fn end_before_start<T: PartialOrd>(end: Bound<&T>, start: Bound<&T>) -> bool {
+    match (end, start) {
+        (Bound::Unbounded, _) | (_, Bound::Unbounded) => false,
+        (Bound::Excluded(a), Bound::Included(b)) => a <= b,
+        (Bound::Excluded(a), Bound::Excluded(b)) => a <= b,
+        (Bound::Included(a), Bound::Excluded(b)) => a <= b,
+        (Bound::Included(a), Bound::Included(b)) => a < b,
+    }
+}
+
+/// `true` if start bound `a` reaches at least as far back as start bound `b` (`a`'s range
+/// starts at or before `b`'s).
+pub fn start_le_start<T: PartialOrd>(a: Bound<&T>, b: Bound<&T>) -> bool {
+    match (a, b) {
+        (Bound::Unbounded, Bound::Unbounded) => true,
+        (Bound::Unbounded, _) => true,
+        (_, Bound::Unbounded) => false,
+        (Bound::Included(a), Bound::Included(b)) => a <= b,
+        (Bound::Excluded(a), Bound::Excluded(b)) => a <= b,
+        (Bound::Included(a), Bound::Excluded(b)) => a <= b,
+        (Bound::Excluded(a), Bound::Included(b)) => a < b,
+    }
+}
+
+/// `true` if end bound `a` reaches no further than end bound `b`.
+pub fn end_le_end<T: PartialOrd>(a: Bound<&T>, b: Bound<&T>) -> bool {
+    match (a, b) {
+        (Bound::Unbounded, Bound::Unbounded) => true,
+        (_, Bound::Unbounded) => true,
+        (Bound::Unbounded, _) => false,
+        (Bound::Excluded(a), Bound::Excluded(b)) => a <= b,
+        (Bound::Included(a), Bound::Included(b)) => a <= b,
+        (Bound::Excluded(a), Bound::Included(b)) => a <= b,
+        (Bound::Included(a), Bound::Excluded(b)) => a < b,
+    }
+}
+
+/// Shift an owned bound's value by `base`, used by `compose` to translate an inner range's
+/// bounds into the outer range's coordinate space. `Unbounded` is left untouched.
+pub fn shift_bound<T>(bound: Bound<T>, base: &T) -> Bound<T>
+where
+    T: Add<T, Output = T> + Clone,
+{
+    match bound {
+        Bound::Included(value) => Bound::Included(base.clone() + value),
+        Bound::Excluded(value) => Bound::Excluded(base.clone() + value),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_eq_compares_kind_and_value() {
+        assert!(bounds_eq(Bound::Included(&3), Bound::Included(&3)));
+        assert!(bounds_eq(Bound::Excluded(&3), Bound::Excluded(&3)));
+        assert!(bounds_eq(Bound::Unbounded::<&i32>, Bound::Unbounded));
+        assert!(!bounds_eq(Bound::Included(&3), Bound::Excluded(&3)));
+        assert!(!bounds_eq(Bound::Included(&3), Bound::Included(&4)));
+    }
+
+    #[test]
+    fn end_le_end_same_value_excluded_vs_included() {
+        // An exclusive end at 5 reaches no further than an inclusive end at 5: both describe
+        // "up to but not containing 5" vs. "up to and including 5".
+        assert!(end_le_end(Bound::Excluded(&5), Bound::Included(&5)));
+        assert!(end_le_end(Bound::Excluded(&5), Bound::Excluded(&5)));
+        // An inclusive end at 5 reaches further than an exclusive end at 5.
+        assert!(!end_le_end(Bound::Included(&5), Bound::Excluded(&5)));
+    }
+
+    #[test]
+    fn end_le_end_unbounded() {
+        assert!(end_le_end(Bound::Included(&5), Bound::Unbounded));
+        assert!(!end_le_end(Bound::Unbounded, Bound::Included(&5)));
+        assert!(end_le_end(Bound::Unbounded::<&i32>, Bound::Unbounded));
+    }
+
+    #[test]
+    fn start_le_start_same_value_included_vs_excluded() {
+        assert!(start_le_start(Bound::Included(&0), Bound::Excluded(&0)));
+        assert!(!start_le_start(Bound::Excluded(&0), Bound::Included(&0)));
+    }
+
+    #[test]
+    fn start_le_start_unbounded() {
+        assert!(start_le_start(Bound::Unbounded::<&i32>, Bound::Included(&0)));
+        assert!(!start_le_start(Bound::Included(&0), Bound::Unbounded));
+    }
+
+    #[test]
+    fn bounds_are_adjacent_excluded_meets_included() {
+        assert!(bounds_are_adjacent(Bound::Excluded(&3), Bound::Included(&3)));
+        assert!(bounds_are_adjacent(Bound::Included(&3), Bound::Excluded(&3)));
+        assert!(!bounds_are_adjacent(Bound::Included(&3), Bound::Included(&3)));
+        assert!(!bounds_are_adjacent(Bound::Excluded(&3), Bound::Excluded(&4)));
+    }
+
+    #[test]
+    fn end_before_start_touching_counts_as_before() {
+        // An excluded end at 3 and an included start at 3 describe the same adjacency point
+        // `bounds_are_adjacent` recognizes, but here there's no shared element either way, so
+        // both directions of a touching boundary count as "before".
+        assert!(end_before_start(Bound::Excluded(&3), Bound::Included(&3)));
+        assert!(end_before_start(Bound::Included(&3), Bound::Excluded(&3)));
+        assert!(!end_before_start(Bound::Included(&3), Bound::Included(&3)));
+        assert!(!end_before_start(Bound::Unbounded, Bound::Included(&3)));
+    }
+
+    #[test]
+    fn shift_bound_translates_owned_values() {
+        assert_eq!(shift_bound(Bound::Included(2), &10), Bound::Included(12));
+        assert_eq!(shift_bound(Bound::Excluded(2), &10), Bound::Excluded(12));
+        assert_eq!(shift_bound(Bound::Unbounded::<i32>, &10), Bound::Unbounded);
+    }
+}
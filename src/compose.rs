@@ -1,21 +1,106 @@
-use std::ops::Range;
+use crate::bounds::{end_le_end, shift_bound, FromBounds, IntoBounds};
+use crate::error::RangeError;
+use std::ops::{Add, Bound, Range, RangeFrom, RangeInclusive};
 
+/// Implementation detail behind [`crate::RangeExt::compose`] and
+/// [`crate::RangeExt::try_compose`].
+///
+/// `rhs`'s bounds are shifted into `self`'s coordinate space, so the composed range has the
+/// same shape as `rhs` — only the values move. `self` needs a concrete start to shift by, so
+/// only the range kinds that have one (`Range`, `RangeFrom`, `RangeInclusive`) can be the
+/// outer range. An inner range with an unbounded start (`RangeTo`, `RangeToInclusive`) would
+/// reach back before that start and is rejected with [`RangeError::ComposeUnderflow`]; one
+/// with an unbounded end (`RangeFrom`) only fits a `RangeFrom` outer range and is otherwise
+/// rejected with [`RangeError::ComposeOverflow`].
 pub trait RangeCompose<Rhs> {
     type Output;
 
-    fn compose(&self, rhs: &Rhs) -> Self::Output;
+    /// Fallible counterpart to [`Self::compose`]; returns [`RangeError::ComposeUnderflow`] if
+    /// the composed range would start before `self`'s start (e.g. `rhs` has a negative start),
+    /// or [`RangeError::ComposeOverflow`] if it would extend past `self`'s end, instead of
+    /// panicking.
+    fn try_compose(&self, rhs: &Rhs) -> Result<Self::Output, RangeError>;
+
+    fn compose(&self, rhs: &Rhs) -> Self::Output {
+        self.try_compose(rhs).unwrap()
+    }
+}
+
+fn shift_fits<T: PartialOrd>(shifted_end: &Bound<T>, outer_end: Bound<&T>) -> bool {
+    end_le_end(shifted_end.as_ref(), outer_end)
+}
+
+fn shift_starts_fit<T: PartialOrd>(shifted_start: &Bound<T>, outer_start: &T) -> bool {
+    match shifted_start {
+        Bound::Included(start) | Bound::Excluded(start) => start >= outer_start,
+        Bound::Unbounded => false,
+    }
+}
+
+impl<T, Rhs> RangeCompose<Rhs> for Range<T>
+where
+    T: PartialOrd + Clone + Add<T, Output = T>,
+    Rhs: IntoBounds<Item = T> + FromBounds<T> + Clone,
+{
+    type Output = Rhs;
+
+    fn try_compose(&self, rhs: &Rhs) -> Result<Rhs, RangeError> {
+        let (start, end) = rhs.clone().into_bounds();
+        let start = shift_bound(start, &self.start);
+        let end = shift_bound(end, &self.start);
+
+        if !shift_starts_fit(&start, &self.start) {
+            return Err(RangeError::ComposeUnderflow);
+        }
+
+        if !shift_fits(&end, Bound::Excluded(&self.end)) {
+            return Err(RangeError::ComposeOverflow);
+        }
+
+        Ok(Rhs::from_bounds((start, end)))
+    }
 }
 
-impl<T> RangeCompose<Range<T>> for Range<T>
+impl<T, Rhs> RangeCompose<Rhs> for RangeFrom<T>
 where
-    T: std::cmp::PartialOrd + std::ops::Add<T, Output = T> + Clone,
+    T: PartialOrd + Clone + Add<T, Output = T>,
+    Rhs: IntoBounds<Item = T> + FromBounds<T> + Clone,
 {
-    type Output = Range<T>;
+    type Output = Rhs;
+
+    fn try_compose(&self, rhs: &Rhs) -> Result<Rhs, RangeError> {
+        let (start, end) = rhs.clone().into_bounds();
+        let start = shift_bound(start, &self.start);
+        let end = shift_bound(end, &self.start);
+
+        if !shift_starts_fit(&start, &self.start) {
+            return Err(RangeError::ComposeUnderflow);
+        }
+
+        Ok(Rhs::from_bounds((start, end)))
+    }
+}
+
+impl<T, Rhs> RangeCompose<Rhs> for RangeInclusive<T>
+where
+    T: PartialOrd + Clone + Add<T, Output = T>,
+    Rhs: IntoBounds<Item = T> + FromBounds<T> + Clone,
+{
+    type Output = Rhs;
+
+    fn try_compose(&self, rhs: &Rhs) -> Result<Rhs, RangeError> {
+        let (start, end) = rhs.clone().into_bounds();
+        let start = shift_bound(start, self.start());
+        let end = shift_bound(end, self.start());
+
+        if !shift_starts_fit(&start, self.start()) {
+            return Err(RangeError::ComposeUnderflow);
+        }
 
-    fn compose(&self, rhs: &Range<T>) -> Self::Output {
-        assert!(self.start.clone() <= self.start.clone() + rhs.start.clone());
-        assert!((self.start.clone() + rhs.end.clone()) <= self.end.clone());
+        if !shift_fits(&end, Bound::Included(self.end())) {
+            return Err(RangeError::ComposeOverflow);
+        }
 
-        (self.start.clone() + rhs.start.clone())..(self.start.clone() + rhs.end.clone())
+        Ok(Rhs::from_bounds((start, end)))
     }
 }
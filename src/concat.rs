@@ -0,0 +1,53 @@
+use crate::bounds::{
+    bounds_are_adjacent, Combine, CombinedRange, Combiner, EndKind, FromBounds, IntoBounds,
+    StartKind,
+};
+use crate::error::RangeError;
+
+/// Implementation detail behind [`crate::RangeExt::concat`] and [`crate::RangeExt::try_concat`].
+///
+/// `Output` is resolved from `Self`'s start kind and `Rhs`'s end kind: e.g. a half-open range
+/// (`Included` start) concatenated with an unbounded one (`Unbounded` end) yields a
+/// `RangeFrom`, matching `(0..3).concat(3..) == 0..`.
+///
+/// Adjacency is checked purely on bound shape (see [`crate::bounds::bounds_are_adjacent`]), so
+/// `self` needs a half-open end (`Range`, `RangeTo`) to ever be considered adjacent to `after`.
+/// An inclusive-ended `self` (`RangeInclusive`, `RangeToInclusive`) can't be concatenated to
+/// anything this way, since stepping from its last included value to the next one would need a
+/// `T: Successor` bound this trait doesn't require.
+pub trait RangeConcat<Rhs> {
+    type Output;
+
+    /// Fallible counterpart to [`Self::concat`]; returns [`RangeError::NotAdjacent`] instead
+    /// of panicking if `after` doesn't immediately follow `self`.
+    fn try_concat(self, after: Rhs) -> Result<Self::Output, RangeError>;
+
+    fn concat(self, after: Rhs) -> Self::Output
+    where
+        Self: Sized,
+    {
+        self.try_concat(after).unwrap()
+    }
+}
+
+impl<Lhs, Rhs> RangeConcat<Rhs> for Lhs
+where
+    Lhs: IntoBounds + StartKind,
+    Rhs: IntoBounds<Item = Lhs::Item> + EndKind,
+    Lhs::Item: PartialEq,
+    Combiner: Combine<Lhs::Item, Lhs::Kind, Rhs::Kind>,
+    CombinedRange<Lhs::Kind, Rhs::Kind, Lhs::Item>: FromBounds<Lhs::Item>,
+{
+    type Output = CombinedRange<Lhs::Kind, Rhs::Kind, Lhs::Item>;
+
+    fn try_concat(self, after: Rhs) -> Result<Self::Output, RangeError> {
+        let (start, self_end) = self.into_bounds();
+        let (after_start, end) = after.into_bounds();
+
+        if !bounds_are_adjacent(self_end.as_ref(), after_start.as_ref()) {
+            return Err(RangeError::NotAdjacent);
+        }
+
+        Ok(Self::Output::from_bounds((start, end)))
+    }
+}
@@ -1,26 +1,109 @@
-use super::RangeContainsExt;
-use std::ops::Range;
+use crate::bounds::{
+    end_le_end, start_le_start, Combine, CombinedRange, Combiner, EndKind, ExcludedEnd,
+    FromBounds, IncludedStart, IntoBounds, StartKind,
+};
+use crate::error::RangeError;
+use crate::step::Successor;
+use std::ops::{Bound, Range, RangeInclusive};
 
-pub trait RangeCut<Cut> {
-    fn cut(self, middle: &Cut) -> (Self, Self)
+/// Implementation detail behind [`crate::RangeExt::cut`] and [`crate::RangeExt::try_cut`].
+///
+/// `Before` keeps `self`'s start kind and ends where `middle` begins; `After` starts where
+/// `middle` ends and keeps `self`'s end kind. Cutting by an inclusive `middle` (`RangeInclusive`)
+/// removes its end value too, so `After` has to start one step past it, which is why that impl
+/// additionally requires `T: Successor`.
+pub trait RangeCut<Middle> {
+    type Before;
+    type After;
+
+    /// Fallible counterpart to [`Self::cut`]; returns [`RangeError::EmptyMiddle`] if `middle`
+    /// contains no elements, [`RangeError::MiddleOutOfBounds`] if `middle` isn't fully
+    /// contained in `self`, or (for an inclusive `middle`) [`RangeError::SuccessorOverflow`] if
+    /// `middle`'s end is already `T`'s maximum value and `After`'s start can't step past it,
+    /// instead of panicking.
+    fn try_cut(self, middle: &Middle) -> Result<(Self::Before, Self::After), RangeError>;
+
+    fn cut(self, middle: &Middle) -> (Self::Before, Self::After)
     where
-        Self: Sized;
+        Self: Sized,
+    {
+        self.try_cut(middle).unwrap()
+    }
 }
 
-impl<T> RangeCut<Range<T>> for Range<T>
+impl<T, Self_> RangeCut<Range<T>> for Self_
 where
     T: PartialOrd + Clone,
+    Self_: IntoBounds<Item = T> + StartKind + EndKind,
+    Combiner: Combine<T, <Self_ as StartKind>::Kind, ExcludedEnd>,
+    Combiner: Combine<T, IncludedStart, <Self_ as EndKind>::Kind>,
+    CombinedRange<<Self_ as StartKind>::Kind, ExcludedEnd, T>: FromBounds<T>,
+    CombinedRange<IncludedStart, <Self_ as EndKind>::Kind, T>: FromBounds<T>,
 {
-    fn cut(self, middle: &Self) -> (Self, Self) {
-        assert!(self.contains(&middle.start));
-        assert!(self.contains_or_ends_at(&middle.end));
-        assert!(middle.start < middle.end);
+    type Before = CombinedRange<<Self_ as StartKind>::Kind, ExcludedEnd, T>;
+    type After = CombinedRange<IncludedStart, <Self_ as EndKind>::Kind, T>;
+
+    fn try_cut(self, middle: &Range<T>) -> Result<(Self::Before, Self::After), RangeError> {
+        if middle.start >= middle.end {
+            return Err(RangeError::EmptyMiddle);
+        }
+
+        let (self_start, self_end) = self.into_bounds();
+        let middle_start = Bound::Included(middle.start.clone());
+        let middle_end_excluded = Bound::Excluded(middle.end.clone());
+
+        if !start_le_start(self_start.as_ref(), middle_start.as_ref())
+            || !end_le_end(middle_end_excluded.as_ref(), self_end.as_ref())
+        {
+            return Err(RangeError::MiddleOutOfBounds);
+        }
+
+        Ok((
+            Self::Before::from_bounds((self_start, Bound::Excluded(middle.start.clone()))),
+            Self::After::from_bounds((Bound::Included(middle.end.clone()), self_end)),
+        ))
+    }
+}
+
+impl<T, Self_> RangeCut<RangeInclusive<T>> for Self_
+where
+    T: PartialOrd + Clone + Successor,
+    Self_: IntoBounds<Item = T> + StartKind + EndKind,
+    Combiner: Combine<T, <Self_ as StartKind>::Kind, ExcludedEnd>,
+    Combiner: Combine<T, IncludedStart, <Self_ as EndKind>::Kind>,
+    CombinedRange<<Self_ as StartKind>::Kind, ExcludedEnd, T>: FromBounds<T>,
+    CombinedRange<IncludedStart, <Self_ as EndKind>::Kind, T>: FromBounds<T>,
+{
+    type Before = CombinedRange<<Self_ as StartKind>::Kind, ExcludedEnd, T>;
+    type After = CombinedRange<IncludedStart, <Self_ as EndKind>::Kind, T>;
+
+    fn try_cut(
+        self,
+        middle: &RangeInclusive<T>,
+    ) -> Result<(Self::Before, Self::After), RangeError> {
+        if middle.start() > middle.end() {
+            return Err(RangeError::EmptyMiddle);
+        }
+
+        let (self_start, self_end) = self.into_bounds();
+        let middle_start = Bound::Included(middle.start().clone());
+        let middle_end = Bound::Included(middle.end().clone());
+
+        if !start_le_start(self_start.as_ref(), middle_start.as_ref())
+            || !end_le_end(middle_end.as_ref(), self_end.as_ref())
+        {
+            return Err(RangeError::MiddleOutOfBounds);
+        }
 
-        assert!(self.start <= middle.start);
+        let after_start = middle
+            .end()
+            .clone()
+            .checked_successor()
+            .ok_or(RangeError::SuccessorOverflow)?;
 
-        (
-            self.start..middle.start.clone(),
-            middle.end.clone()..self.end,
-        )
+        Ok((
+            Self::Before::from_bounds((self_start, Bound::Excluded(middle.start().clone()))),
+            Self::After::from_bounds((Bound::Included(after_start), self_end)),
+        ))
     }
 }
@@ -0,0 +1,28 @@
+use crate::multi_range::MultiRange;
+use std::ops::Range;
+
+/// Implementation detail behind [`crate::RangeExt::cut_many`].
+pub trait CutMany {
+    type Item;
+
+    fn cut_many(self, middles: &[Range<Self::Item>]) -> MultiRange<Self::Item>;
+}
+
+impl<T> CutMany for Range<T>
+where
+    T: PartialOrd + Clone,
+{
+    type Item = T;
+
+    fn cut_many(self, middles: &[Range<T>]) -> MultiRange<T> {
+        let mut cuts = MultiRange::new();
+        for middle in middles {
+            cuts.insert(middle.clone());
+        }
+
+        let mut whole = MultiRange::new();
+        whole.insert(self);
+
+        whole.difference(&cuts)
+    }
+}
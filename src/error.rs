@@ -0,0 +1,71 @@
+//! The error type shared by every `try_*` counterpart in [`crate::RangeExt`].
+
+use std::fmt;
+
+/// The specific precondition a `try_*` operation found violated, in place of the panic its
+/// panicking counterpart raises.
+///
+/// Returned by [`crate::RangeExt::try_concat`], [`crate::RangeExt::try_remove_prefix`],
+/// [`crate::RangeExt::try_remove_suffix`], [`crate::RangeExt::try_cut`], and
+/// [`crate::RangeExt::try_compose`], so callers building ranges from untrusted or derived
+/// offsets get a recoverable error instead of a panic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeError {
+    /// `after` does not immediately follow `self`, as [`crate::RangeExt::concat`] requires.
+    ///
+    /// Carries no payload: reporting the size of the gap would need a `T: Sub<Output = T>` (or
+    /// similar) bound, but `concat` is checked purely on bound shape (see
+    /// [`crate::bounds::bounds_are_adjacent`]) and works for any `T: PartialEq`, so there's no
+    /// way to compute a distance generically here.
+    NotAdjacent,
+    /// `prefix` does not start where `self` does, as [`crate::RangeExt::remove_prefix`]
+    /// requires.
+    NotAPrefix,
+    /// `prefix` extends past `self`'s end.
+    PrefixOutOfBounds,
+    /// `suffix` does not end where `self` does, as [`crate::RangeExt::remove_suffix`]
+    /// requires.
+    NotASuffix,
+    /// `suffix` doesn't start within `self`.
+    SuffixOutOfBounds,
+    /// `suffix` has no concrete start bound (a `RangeTo` or `RangeToInclusive`), so there
+    /// is no value to reinterpret as the remainder's excluded end.
+    UnboundedSuffixStart,
+    /// `middle` is not fully contained in `self`, as [`crate::RangeExt::cut`] requires.
+    MiddleOutOfBounds,
+    /// `middle` contains no elements.
+    EmptyMiddle,
+    /// Stepping past an inclusive bound's value to find its exclusive equivalent would
+    /// overflow `T`.
+    SuccessorOverflow,
+    /// The composed range would extend past `self`'s end.
+    ComposeOverflow,
+    /// The composed range would start before `self`'s start.
+    ComposeUnderflow,
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeError::NotAdjacent => write!(f, "`after` does not immediately follow `self`"),
+            RangeError::NotAPrefix => write!(f, "`prefix` does not start where `self` does"),
+            RangeError::PrefixOutOfBounds => write!(f, "`prefix` extends past `self`'s end"),
+            RangeError::NotASuffix => write!(f, "`suffix` does not end where `self` does"),
+            RangeError::SuffixOutOfBounds => write!(f, "`suffix` doesn't start within `self`"),
+            RangeError::UnboundedSuffixStart => {
+                write!(f, "`suffix` must have a concrete start bound")
+            }
+            RangeError::MiddleOutOfBounds => {
+                write!(f, "`middle` is not fully contained in `self`")
+            }
+            RangeError::EmptyMiddle => write!(f, "`middle` must not be empty"),
+            RangeError::SuccessorOverflow => {
+                write!(f, "stepping past the inclusive bound's value overflows `T`")
+            }
+            RangeError::ComposeOverflow => write!(f, "composed range overflows `self`"),
+            RangeError::ComposeUnderflow => write!(f, "composed range starts before `self`"),
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
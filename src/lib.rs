@@ -1,44 +1,37 @@
-use std::ops::{Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+mod bounds;
+mod compose;
+mod concat;
+mod cut;
+mod cut_many;
+mod error;
+mod multi_range;
+mod relation;
+mod remove_prefix;
+mod remove_suffix;
+mod step;
 
-trait RangeContainsExt<T> {
-    fn contains_or_ends_at(&self, index: &T) -> bool;
-}
-
-impl<T> RangeContainsExt<T> for Range<T>
-where
-    T: PartialOrd,
-{
-    fn contains_or_ends_at(&self, index: &T) -> bool {
-        index <= &self.end
-    }
-}
-
-trait RangeCompose<Rhs> {
-    type Output;
+pub use error::RangeError;
+pub use multi_range::MultiRange;
+pub use relation::RangeRelation;
+pub use step::Successor;
 
-    fn compose(&self, rhs: &Rhs) -> Self::Output;
-}
-
-impl<T> RangeCompose<Range<T>> for Range<T>
-where
-    T: std::cmp::PartialOrd + std::ops::Add<T, Output = T> + Clone,
-{
-    type Output = Range<T>;
-
-    fn compose(&self, rhs: &Range<T>) -> Self::Output {
-        assert!(self.start.clone() <= self.start.clone() + rhs.start.clone());
-        assert!((self.start.clone() + rhs.end.clone()) <= self.end.clone());
-
-        (self.start.clone() + rhs.start.clone())..(self.start.clone() + rhs.end.clone())
-    }
-}
+use std::ops::Range;
 
-pub trait RangeExt {
+pub trait RangeExt: Sized {
     /// Concatenate `self` and `after`, panicking if `after` doesn't immediately follow `self`.
     ///
+    /// Works across the whole range-bound family: the output type is whichever std range
+    /// kind has `self`'s start and `after`'s end, so e.g. concatenating a half-open range
+    /// with an unbounded one yields a `RangeFrom`. `self` needs a half-open end (`Range`,
+    /// `RangeTo`) to be adjacent to anything, though: an inclusive-ended `self`
+    /// (`RangeInclusive`, `RangeToInclusive`) always panics, since detecting that its last
+    /// value immediately precedes `after`'s start would need a `T: Successor` bound this
+    /// method doesn't require.
+    ///
     /// ```rust
     /// # use rangecutter::RangeExt;
     /// assert_eq!((0..3).concat(3..4), 0..4);
+    /// assert_eq!((0..3).concat(3..), 0..);
     ///
     /// let arr = [0, 1, 2, 3, 4];
     ///
@@ -51,13 +44,45 @@ pub trait RangeExt {
     /// # use rangecutter::RangeExt;
     /// println!("{:?}", (0..1).concat(3..4));
     /// ```
-    fn concat(self, after: Self) -> Self;
+    fn concat<Rhs>(self, after: Rhs) -> <Self as concat::RangeConcat<Rhs>>::Output
+    where
+        Self: concat::RangeConcat<Rhs>,
+    {
+        concat::RangeConcat::concat(self, after)
+    }
+
+    /// Fallible counterpart to [`RangeExt::concat`]: returns [`RangeError::NotAdjacent`]
+    /// instead of panicking if `after` doesn't immediately follow `self`.
+    ///
+    /// ```rust
+    /// # use rangecutter::RangeExt;
+    /// assert_eq!((0..3).try_concat(3..4), Ok(0..4));
+    /// assert!((0..1).try_concat(3..4).is_err());
+    /// ```
+    fn try_concat<Rhs>(
+        self,
+        after: Rhs,
+    ) -> Result<
+        <Self as concat::RangeConcat<Rhs>>::Output,
+        RangeError,
+    >
+    where
+        Self: concat::RangeConcat<Rhs>,
+    {
+        concat::RangeConcat::try_concat(self, after)
+    }
 
     /// Remove `prefix` from `self`, panicking if it isn't a prefix of `self`.
     ///
+    /// `prefix` can be a `Range`, `RangeTo`, `RangeInclusive` or `RangeToInclusive` with the
+    /// same start as `self` (a `RangeFrom` prefix is not accepted); if it has an inclusive
+    /// end (`RangeInclusive`/`RangeToInclusive`), `T: Successor` is required so the remainder
+    /// can start one step past `prefix`'s last value.
+    ///
     /// ```rust
     /// # use rangecutter::RangeExt;
     /// assert_eq!((0..5).remove_prefix(0..1), 1..5);
+    /// assert_eq!((..5).remove_prefix(..1), 1..5);
     ///
     /// let arr = [0, 1, 2];
     ///
@@ -65,7 +90,36 @@ pub trait RangeExt {
     /// assert_eq!([0      ], arr[0..1]);
     /// assert_eq!([   1, 2], arr[(0..3).remove_prefix(0..1)]);
     /// ```
-    fn remove_prefix(self, prefix: Self) -> Self;
+    fn remove_prefix<Prefix>(
+        self,
+        prefix: Prefix,
+    ) -> <Self as remove_prefix::RemovePrefix<Prefix>>::Output
+    where
+        Self: remove_prefix::RemovePrefix<Prefix>,
+    {
+        remove_prefix::RemovePrefix::remove_prefix(self, prefix)
+    }
+
+    /// Fallible counterpart to [`RangeExt::remove_prefix`]: returns
+    /// [`RangeError::NotAPrefix`] instead of panicking if `prefix` isn't a prefix of `self`.
+    ///
+    /// ```rust
+    /// # use rangecutter::RangeExt;
+    /// assert_eq!((0..5).try_remove_prefix(0..1), Ok(1..5));
+    /// assert!((0..5).try_remove_prefix(1..2).is_err());
+    /// ```
+    fn try_remove_prefix<Prefix>(
+        self,
+        prefix: Prefix,
+    ) -> Result<
+        <Self as remove_prefix::RemovePrefix<Prefix>>::Output,
+        RangeError,
+    >
+    where
+        Self: remove_prefix::RemovePrefix<Prefix>,
+    {
+        remove_prefix::RemovePrefix::try_remove_prefix(self, prefix)
+    }
 
     /// Remove `suffix` from `self`, panicking if it isn't a suffix of `self`.
     ///
@@ -79,14 +133,48 @@ pub trait RangeExt {
     /// assert_eq!([      2], arr[2..3]);
     /// assert_eq!([0, 1   ], arr[(0..3).remove_suffix(2..3)]);
     /// ```
-    fn remove_suffix(self, suffix: Self) -> Self;
+    fn remove_suffix<Suffix>(
+        self,
+        suffix: Suffix,
+    ) -> <Self as remove_suffix::RemoveSuffix<Suffix>>::Output
+    where
+        Self: remove_suffix::RemoveSuffix<Suffix>,
+    {
+        remove_suffix::RemoveSuffix::remove_suffix(self, suffix)
+    }
+
+    /// Fallible counterpart to [`RangeExt::remove_suffix`]: returns
+    /// [`RangeError::NotASuffix`] instead of panicking if `suffix` isn't a suffix of `self`.
+    ///
+    /// ```rust
+    /// # use rangecutter::RangeExt;
+    /// assert_eq!((0..5).try_remove_suffix(3..5), Ok(0..3));
+    /// assert!((0..5).try_remove_suffix(2..3).is_err());
+    /// ```
+    fn try_remove_suffix<Suffix>(
+        self,
+        suffix: Suffix,
+    ) -> Result<
+        <Self as remove_suffix::RemoveSuffix<Suffix>>::Output,
+        RangeError,
+    >
+    where
+        Self: remove_suffix::RemoveSuffix<Suffix>,
+    {
+        remove_suffix::RemoveSuffix::try_remove_suffix(self, suffix)
+    }
 
-    /// Split the range into the section before `middle` starts, and the section that starts where `middle` ends.
-    /// Panics if `middle` contains any elements not in `self`.
+    /// Split the range into the section before `middle` starts, and the section that starts
+    /// where `middle` ends. Panics if `middle` contains any elements not in `self`.
+    ///
+    /// `middle` can be a `Range` or a `RangeInclusive`; cutting by an inclusive `middle`
+    /// requires `T: Successor` so the surviving `after` section can start one step past
+    /// `middle`'s last value.
     ///
     /// ```rust
     /// # use rangecutter::RangeExt;
     /// assert_eq!((0..5).cut(&(1..3)), (0..1, 3..5));
+    /// assert_eq!((0..=9).cut(&(3..=5)), (0..3, 6..=9));
     ///
     /// let arr = [0, 1, 2, 3, 4];
     ///
@@ -107,15 +195,47 @@ pub trait RangeExt {
     ///     [   1, 2      ],
     /// );
     /// ```
-    fn cut<C>(self, middle: &C) -> (Self, Self)
+    fn cut<Middle>(
+        self,
+        middle: &Middle,
+    ) -> (
+        <Self as cut::RangeCut<Middle>>::Before,
+        <Self as cut::RangeCut<Middle>>::After,
+    )
     where
-        Self: Sized,
-        Self: range_cut::RangeCut<C>,
+        Self: cut::RangeCut<Middle>,
     {
-        range_cut::RangeCut::cut(self, middle)
+        cut::RangeCut::cut(self, middle)
     }
 
-    /// Calculate the range such that indexing by the new range has the same result as indexing by `self` and then `rhs`.
+    /// Fallible counterpart to [`RangeExt::cut`]: returns [`RangeError::EmptyMiddle`] if
+    /// `middle` contains no elements, or [`RangeError::MiddleOutOfBounds`] if `middle` isn't
+    /// fully contained in `self`, instead of panicking.
+    ///
+    /// ```rust
+    /// # use rangecutter::RangeExt;
+    /// assert_eq!((0..5).try_cut(&(1..3)), Ok((0..1, 3..5)));
+    /// assert!((0..5).try_cut(&(3..8)).is_err());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn try_cut<Middle>(
+        self,
+        middle: &Middle,
+    ) -> Result<
+        (
+            <Self as cut::RangeCut<Middle>>::Before,
+            <Self as cut::RangeCut<Middle>>::After,
+        ),
+        RangeError,
+    >
+    where
+        Self: cut::RangeCut<Middle>,
+    {
+        cut::RangeCut::try_cut(self, middle)
+    }
+
+    /// Calculate the range such that indexing by the new range has the same result as
+    /// indexing by `self` and then `rhs`.
     ///
     /// ```rust
     /// # use rangecutter::RangeExt;
@@ -126,79 +246,94 @@ pub trait RangeExt {
     ///
     /// assert_eq!(arr[outer.compose(&inner)], arr[outer][inner]);
     /// ```
-    fn compose<Rhs, Output>(&self, rhs: &Rhs) -> Output
+    fn compose<Rhs>(&self, rhs: &Rhs) -> <Self as compose::RangeCompose<Rhs>>::Output
     where
-        Self: RangeCompose<Rhs, Output = Output>,
+        Self: compose::RangeCompose<Rhs>,
     {
-        RangeCompose::compose(self, rhs)
-    }
-}
-
-impl<T> RangeExt for Range<T>
-where
-    T: std::cmp::PartialOrd + std::cmp::PartialEq,
-{
-    fn concat(self, after: Self) -> Self {
-        assert!(self.end == after.start);
-
-        self.start..after.end
+        compose::RangeCompose::compose(self, rhs)
     }
 
-    fn remove_prefix(self, prefix: Self) -> Self {
-        assert!(prefix.start == self.start);
-        assert!(prefix.end <= self.end);
-
-        prefix.end..self.end
-    }
-
-    fn remove_suffix(self, suffix: Self) -> Self {
-        assert!(self.start <= suffix.start);
-        assert!(self.end == suffix.end);
-
-        self.start..suffix.start
+    /// Fallible counterpart to [`RangeExt::compose`]: returns
+    /// [`RangeError::ComposeUnderflow`] if the composed range would start before `self`'s
+    /// start, or [`RangeError::ComposeOverflow`] if it would extend past `self`'s end, instead
+    /// of panicking.
+    ///
+    /// ```rust
+    /// # use rangecutter::RangeExt;
+    /// let outer = 2..4;
+    /// assert_eq!(outer.try_compose(&(1..2)), Ok(3..4));
+    /// assert!((2..4).try_compose(&(1..3)).is_err());
+    /// ```
+    fn try_compose<Rhs>(
+        &self,
+        rhs: &Rhs,
+    ) -> Result<
+        <Self as compose::RangeCompose<Rhs>>::Output,
+        RangeError,
+    >
+    where
+        Self: compose::RangeCompose<Rhs>,
+    {
+        compose::RangeCompose::try_compose(self, rhs)
     }
-}
-
-mod range_cut {
-    use super::RangeContainsExt;
-    use std::ops::Range;
 
-    pub trait RangeCut<Cut> {
-        fn cut(self, middle: &Cut) -> (Self, Self)
-        where
-            Self: Sized;
+    /// Classify how `self` and `other` relate, without panicking.
+    ///
+    /// Useful for guarding a panicking operation ahead of time: `concat` requires
+    /// [`RangeRelation::Adjacent`], `cut` requires [`RangeRelation::Contains`], and so on.
+    ///
+    /// Adjacency is detected purely from bound shape (an `Excluded` end meeting an `Included`
+    /// start, or vice versa), not from `T`'s values, so it only works generically for half-open
+    /// boundaries. Two inclusive-ended/started ranges whose values are consecutive, e.g.
+    /// `(0..=3).relation(&(4..=9))`, report [`RangeRelation::Disjoint`] rather than
+    /// [`RangeRelation::Adjacent`]: recognizing `3` and `4` as consecutive would need a
+    /// `T: Successor` bound this method doesn't require.
+    ///
+    /// ```rust
+    /// # use rangecutter::{RangeExt, RangeRelation};
+    /// assert_eq!((0..3).relation(&(3..5)), RangeRelation::Adjacent);
+    /// assert_eq!((0..3).relation(&(5..8)), RangeRelation::Disjoint);
+    /// assert_eq!((0..5).relation(&(2..8)), RangeRelation::Overlapping);
+    /// assert_eq!((0..5).relation(&(1..3)), RangeRelation::Contains);
+    /// assert_eq!((1..3).relation(&(0..5)), RangeRelation::ContainedBy);
+    /// assert_eq!((0..5).relation(&(0..5)), RangeRelation::Equal);
+    /// ```
+    fn relation(&self, other: &Self) -> RangeRelation
+    where
+        Self: relation::Relation,
+    {
+        relation::Relation::relation(self, other)
     }
 
-    impl<T> RangeCut<Range<T>> for Range<T>
+    /// Remove every range in `middles` from `self`, returning the surviving pieces as a
+    /// [`MultiRange`]. Unlike [`RangeExt::cut`], there's no limit on how many interior
+    /// sections can be removed at once, and the middles may overlap or touch.
+    ///
+    /// ```rust
+    /// # use rangecutter::RangeExt;
+    /// let pieces: Vec<_> = (0..10).cut_many(&[2..4, 6..8]).into_iter().collect();
+    /// assert_eq!(pieces, vec![0..2, 4..6, 8..10]);
+    ///
+    /// let arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let kept: Vec<_> = (0..10)
+    ///     .cut_many(&[2..4, 6..8])
+    ///     .into_iter()
+    ///     .flat_map(|range| arr[range].to_vec())
+    ///     .collect();
+    /// assert_eq!(kept, vec![0, 1, 4, 5, 8, 9]);
+    /// ```
+    fn cut_many(
+        self,
+        middles: &[Range<<Self as cut_many::CutMany>::Item>],
+    ) -> MultiRange<<Self as cut_many::CutMany>::Item>
     where
-        T: PartialOrd + Clone,
+        Self: cut_many::CutMany,
     {
-        fn cut(self, middle: &Self) -> (Self, Self) {
-            assert!(self.contains(&middle.start));
-            assert!(self.contains_or_ends_at(&middle.end));
-            assert!(middle.start < middle.end);
-
-            assert!(self.start <= middle.start);
-
-            (
-                self.start..middle.start.clone(),
-                middle.end.clone()..self.end,
-            )
-        }
+        cut_many::CutMany::cut_many(self, middles)
     }
 }
 
-// impl<T> RangeCut<T> for Range<T>
-// where
-//     T: std::cmp::PartialOrd,
-// {
-//     fn cut(self, middle: T) -> (Self, Self) {
-//         assert!(self.contains(&middle));
-//         assert!(self.contains_or_ends_at(&middle));
-
-//         (self.start..middle, middle..self.end)
-//     }
-// }
+impl<R> RangeExt for R {}
 
 /// TODO: remove
 pub fn add(left: usize, right: usize) -> usize {
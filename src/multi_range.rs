@@ -0,0 +1,232 @@
+//! A disjoint union of ranges, normalized to a canonical sorted, non-overlapping, non-adjacent
+//! form.
+//!
+//! Where [`crate::RangeExt::cut`] can only remove a single interior span and hand back exactly
+//! two pieces, [`MultiRange`] represents the result of removing, intersecting, or unioning any
+//! number of them at once.
+
+use std::ops::Range;
+
+/// A set of `Range<T>`s, kept sorted by start and normalized so no two members overlap or
+/// touch: any overlapping or adjacent ranges are always coalesced into one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiRange<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> MultiRange<T> {
+    /// An empty set of ranges.
+    pub fn new() -> Self {
+        MultiRange { ranges: Vec::new() }
+    }
+}
+
+impl<T> Default for MultiRange<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> MultiRange<T>
+where
+    T: PartialOrd + Clone,
+{
+    /// Add `range` to the set, merging it with any existing member it overlaps or touches.
+    ///
+    /// Empty ranges (`range.start >= range.end`) are ignored.
+    ///
+    /// ```rust
+    /// # use rangecutter::MultiRange;
+    /// let mut set = MultiRange::new();
+    /// set.insert(0..3);
+    /// set.insert(6..9);
+    /// set.insert(3..6); // adjacent to both existing members, merges them all
+    /// assert_eq!(set.into_iter().collect::<Vec<_>>(), vec![0..9]);
+    ///
+    /// let mut empty = MultiRange::new();
+    /// empty.insert(5..5); // empty range, ignored
+    /// assert_eq!(empty.into_iter().collect::<Vec<_>>(), Vec::<std::ops::Range<i32>>::new());
+    /// ```
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.start >= range.end {
+            return;
+        }
+
+        self.ranges.push(range);
+        self.ranges
+            .sort_by(|a, b| a.start.partial_cmp(&b.start).expect("unorderable range bounds"));
+
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(self.ranges.len());
+        for range in self.ranges.drain(..) {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => {
+                    if range.end > last.end {
+                        last.end = range.end;
+                    }
+                }
+                _ => merged.push(range),
+            }
+        }
+        self.ranges = merged;
+    }
+
+    /// The ranges covered by `self`, `other`, or both.
+    ///
+    /// ```rust
+    /// # use rangecutter::MultiRange;
+    /// let mut a = MultiRange::new();
+    /// a.insert(0..5);
+    /// a.insert(10..15);
+    ///
+    /// let mut b = MultiRange::new();
+    /// b.insert(3..12);
+    ///
+    /// // 0..5 and 3..12 overlap, 3..12 and 10..15 overlap, so everything merges into one span.
+    /// assert_eq!(a.union(&b).into_iter().collect::<Vec<_>>(), vec![0..15]);
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in &other.ranges {
+            result.insert(range.clone());
+        }
+        result
+    }
+
+    /// The ranges covered by both `self` and `other`.
+    ///
+    /// ```rust
+    /// # use rangecutter::MultiRange;
+    /// let mut a = MultiRange::new();
+    /// a.insert(0..5);
+    /// a.insert(10..15);
+    ///
+    /// let mut b = MultiRange::new();
+    /// b.insert(3..12);
+    ///
+    /// assert_eq!(a.intersection(&b).into_iter().collect::<Vec<_>>(), vec![3..5, 10..12]);
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let a = &self.ranges[i];
+            let b = &other.ranges[j];
+
+            let start = if a.start >= b.start {
+                a.start.clone()
+            } else {
+                b.start.clone()
+            };
+            let end = if a.end <= b.end {
+                a.end.clone()
+            } else {
+                b.end.clone()
+            };
+
+            if start < end {
+                ranges.push(start..end);
+            }
+
+            if a.end <= b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        MultiRange { ranges }
+    }
+
+    /// The ranges covered by `self` but not `other`.
+    ///
+    /// ```rust
+    /// # use rangecutter::MultiRange;
+    /// let mut a = MultiRange::new();
+    /// a.insert(0..5);
+    /// a.insert(10..15);
+    ///
+    /// let mut b = MultiRange::new();
+    /// b.insert(3..12);
+    ///
+    /// assert_eq!(a.difference(&b).into_iter().collect::<Vec<_>>(), vec![0..3, 12..15]);
+    ///
+    /// // `other` splitting a single member into two surviving pieces.
+    /// let mut whole = MultiRange::new();
+    /// whole.insert(0..10);
+    /// let mut middles = MultiRange::new();
+    /// middles.insert(2..4);
+    /// middles.insert(6..8);
+    /// assert_eq!(
+    ///     whole.difference(&middles).into_iter().collect::<Vec<_>>(),
+    ///     vec![0..2, 4..6, 8..10],
+    /// );
+    /// ```
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        let mut cursor: Option<T> = None;
+
+        while i < self.ranges.len() {
+            let a = &self.ranges[i];
+            let start = cursor.clone().unwrap_or_else(|| a.start.clone());
+
+            if start >= a.end {
+                i += 1;
+                cursor = None;
+                continue;
+            }
+
+            let Some(b) = other.ranges.get(j) else {
+                ranges.push(start..a.end.clone());
+                i += 1;
+                cursor = None;
+                continue;
+            };
+
+            if b.end <= start {
+                j += 1;
+                continue;
+            }
+
+            if b.start >= a.end {
+                ranges.push(start..a.end.clone());
+                i += 1;
+                cursor = None;
+                continue;
+            }
+
+            if b.start > start {
+                ranges.push(start..b.start.clone());
+            }
+
+            if b.end < a.end {
+                cursor = Some(b.end.clone());
+                j += 1;
+            } else {
+                i += 1;
+                cursor = None;
+            }
+        }
+
+        MultiRange { ranges }
+    }
+}
+
+impl<T> IntoIterator for MultiRange<T> {
+    type Item = Range<T>;
+    type IntoIter = std::vec::IntoIter<Range<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a MultiRange<T> {
+    type Item = &'a Range<T>;
+    type IntoIter = std::slice::Iter<'a, Range<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.iter()
+    }
+}
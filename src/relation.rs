@@ -0,0 +1,67 @@
+use crate::bounds::{
+    bounds_are_adjacent, bounds_eq, end_before_start, end_le_end, start_le_start, BorrowedBounds,
+};
+
+/// How two ranges of the same type relate to each other, as returned by
+/// [`crate::RangeExt::relation`].
+///
+/// Lets callers guard a panicking operation without having to catch the panic: `cut` requires
+/// [`RangeRelation::Contains`], `concat` requires [`RangeRelation::Adjacent`], and so on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeRelation {
+    /// The ranges share no elements and aren't adjacent.
+    Disjoint,
+    /// The ranges share no elements, but one picks up exactly where the other ends.
+    ///
+    /// Only detected for half-open boundaries (an `Excluded` end meeting an `Included` start):
+    /// two inclusive-ended/started ranges with consecutive values, like `0..=3` and `4..=9`,
+    /// report [`RangeRelation::Disjoint`] instead, since recognizing `3` and `4` as consecutive
+    /// needs a `T: Successor` bound this classification doesn't require.
+    Adjacent,
+    /// The ranges share some, but not all, elements.
+    Overlapping,
+    /// `self` contains every element of `other`, and at least one more.
+    Contains,
+    /// `other` contains every element of `self`, and at least one more.
+    ContainedBy,
+    /// The ranges contain exactly the same elements.
+    Equal,
+}
+
+/// Implementation detail behind [`crate::RangeExt::relation`].
+pub trait Relation {
+    fn relation(&self, other: &Self) -> RangeRelation;
+}
+
+impl<R> Relation for R
+where
+    R: BorrowedBounds,
+    R::Item: PartialOrd,
+{
+    fn relation(&self, other: &Self) -> RangeRelation {
+        let (a_start, a_end) = (self.start_bound(), self.end_bound());
+        let (b_start, b_end) = (other.start_bound(), other.end_bound());
+
+        if bounds_eq(a_start, b_start) && bounds_eq(a_end, b_end) {
+            return RangeRelation::Equal;
+        }
+
+        if bounds_are_adjacent(a_end, b_start) || bounds_are_adjacent(b_end, a_start) {
+            return RangeRelation::Adjacent;
+        }
+
+        if end_before_start(a_end, b_start) || end_before_start(b_end, a_start) {
+            return RangeRelation::Disjoint;
+        }
+
+        if start_le_start(a_start, b_start) && end_le_end(b_end, a_end) {
+            return RangeRelation::Contains;
+        }
+
+        if start_le_start(b_start, a_start) && end_le_end(a_end, b_end) {
+            return RangeRelation::ContainedBy;
+        }
+
+        RangeRelation::Overlapping
+    }
+}
@@ -0,0 +1,156 @@
+use crate::bounds::{
+    bounds_eq, end_le_end, Combine, CombinedRange, Combiner, EndKind, FromBounds, IncludedStart,
+    IntoBounds,
+};
+use crate::error::RangeError;
+use crate::step::Successor;
+use std::ops::{Bound, Range, RangeInclusive, RangeTo, RangeToInclusive};
+
+/// Implementation detail behind [`crate::RangeExt::remove_prefix`] and
+/// [`crate::RangeExt::try_remove_prefix`].
+///
+/// Half-open prefixes (`Range`, `RangeTo`) need nothing more than reinterpreting their
+/// excluded end as the remainder's included start. Inclusive prefixes (`RangeInclusive`,
+/// `RangeToInclusive`) remove their end value too, so the remainder has to start one step
+/// past it, which is why those impls additionally require `T: Successor`.
+pub trait RemovePrefix<Prefix> {
+    type Output;
+
+    /// Fallible counterpart to [`Self::remove_prefix`]; returns [`RangeError::NotAPrefix`] if
+    /// `prefix` doesn't start where `self` does, [`RangeError::PrefixOutOfBounds`] if it
+    /// extends past `self`'s end, or (for an inclusive `prefix`) [`RangeError::SuccessorOverflow`]
+    /// if `prefix`'s end is already `T`'s maximum value and the remainder can't step past it,
+    /// instead of panicking.
+    fn try_remove_prefix(self, prefix: Prefix) -> Result<Self::Output, RangeError>;
+
+    fn remove_prefix(self, prefix: Prefix) -> Self::Output
+    where
+        Self: Sized,
+    {
+        self.try_remove_prefix(prefix).unwrap()
+    }
+}
+
+impl<T, Self_> RemovePrefix<Range<T>> for Self_
+where
+    T: PartialOrd,
+    Self_: IntoBounds<Item = T> + EndKind,
+    Combiner: Combine<T, IncludedStart, Self_::Kind>,
+    CombinedRange<IncludedStart, Self_::Kind, T>: FromBounds<T>,
+{
+    type Output = CombinedRange<IncludedStart, Self_::Kind, T>;
+
+    fn try_remove_prefix(self, prefix: Range<T>) -> Result<Self::Output, RangeError> {
+        let (self_start, end) = self.into_bounds();
+        let (prefix_start, prefix_end) = prefix.into_bounds();
+
+        if !bounds_eq(self_start.as_ref(), prefix_start.as_ref()) {
+            return Err(RangeError::NotAPrefix);
+        }
+
+        if !end_le_end(prefix_end.as_ref(), end.as_ref()) {
+            return Err(RangeError::PrefixOutOfBounds);
+        }
+
+        let start = match prefix_end {
+            Bound::Excluded(value) => Bound::Included(value),
+            _ => unreachable!("Range always has an excluded end"),
+        };
+
+        Ok(Self::Output::from_bounds((start, end)))
+    }
+}
+
+impl<T, Self_> RemovePrefix<RangeTo<T>> for Self_
+where
+    T: PartialOrd,
+    Self_: IntoBounds<Item = T> + EndKind,
+    Combiner: Combine<T, IncludedStart, Self_::Kind>,
+    CombinedRange<IncludedStart, Self_::Kind, T>: FromBounds<T>,
+{
+    type Output = CombinedRange<IncludedStart, Self_::Kind, T>;
+
+    fn try_remove_prefix(self, prefix: RangeTo<T>) -> Result<Self::Output, RangeError> {
+        let (self_start, end) = self.into_bounds();
+        let (prefix_start, prefix_end) = prefix.into_bounds();
+
+        if !bounds_eq(self_start.as_ref(), prefix_start.as_ref()) {
+            return Err(RangeError::NotAPrefix);
+        }
+
+        if !end_le_end(prefix_end.as_ref(), end.as_ref()) {
+            return Err(RangeError::PrefixOutOfBounds);
+        }
+
+        let start = match prefix_end {
+            Bound::Excluded(value) => Bound::Included(value),
+            _ => unreachable!("RangeTo always has an excluded end"),
+        };
+
+        Ok(Self::Output::from_bounds((start, end)))
+    }
+}
+
+impl<T, Self_> RemovePrefix<RangeInclusive<T>> for Self_
+where
+    T: PartialOrd + Successor,
+    Self_: IntoBounds<Item = T> + EndKind,
+    Combiner: Combine<T, IncludedStart, Self_::Kind>,
+    CombinedRange<IncludedStart, Self_::Kind, T>: FromBounds<T>,
+{
+    type Output = CombinedRange<IncludedStart, Self_::Kind, T>;
+
+    fn try_remove_prefix(self, prefix: RangeInclusive<T>) -> Result<Self::Output, RangeError> {
+        let (self_start, end) = self.into_bounds();
+        let (prefix_start, prefix_end) = prefix.into_bounds();
+
+        if !bounds_eq(self_start.as_ref(), prefix_start.as_ref()) {
+            return Err(RangeError::NotAPrefix);
+        }
+
+        if !end_le_end(prefix_end.as_ref(), end.as_ref()) {
+            return Err(RangeError::PrefixOutOfBounds);
+        }
+
+        let start = match prefix_end {
+            Bound::Included(value) => {
+                Bound::Included(value.checked_successor().ok_or(RangeError::SuccessorOverflow)?)
+            }
+            _ => unreachable!("RangeInclusive always has an included end"),
+        };
+
+        Ok(Self::Output::from_bounds((start, end)))
+    }
+}
+
+impl<T, Self_> RemovePrefix<RangeToInclusive<T>> for Self_
+where
+    T: PartialOrd + Successor,
+    Self_: IntoBounds<Item = T> + EndKind,
+    Combiner: Combine<T, IncludedStart, Self_::Kind>,
+    CombinedRange<IncludedStart, Self_::Kind, T>: FromBounds<T>,
+{
+    type Output = CombinedRange<IncludedStart, Self_::Kind, T>;
+
+    fn try_remove_prefix(self, prefix: RangeToInclusive<T>) -> Result<Self::Output, RangeError> {
+        let (self_start, end) = self.into_bounds();
+        let (prefix_start, prefix_end) = prefix.into_bounds();
+
+        if !bounds_eq(self_start.as_ref(), prefix_start.as_ref()) {
+            return Err(RangeError::NotAPrefix);
+        }
+
+        if !end_le_end(prefix_end.as_ref(), end.as_ref()) {
+            return Err(RangeError::PrefixOutOfBounds);
+        }
+
+        let start = match prefix_end {
+            Bound::Included(value) => {
+                Bound::Included(value.checked_successor().ok_or(RangeError::SuccessorOverflow)?)
+            }
+            _ => unreachable!("RangeToInclusive always has an included end"),
+        };
+
+        Ok(Self::Output::from_bounds((start, end)))
+    }
+}
@@ -0,0 +1,62 @@
+use crate::bounds::{
+    bounds_eq, end_le_end, start_le_start, Combine, CombinedRange, Combiner, ExcludedEnd,
+    FromBounds, IntoBounds, StartKind,
+};
+use crate::error::RangeError;
+use std::ops::Bound;
+
+/// Implementation detail behind [`crate::RangeExt::remove_suffix`] and
+/// [`crate::RangeExt::try_remove_suffix`].
+///
+/// Unlike [`crate::remove_prefix`], this never needs `T: Successor`: turning a suffix's
+/// included start into the remainder's excluded end is just a reinterpretation of the same
+/// value, not a step to the next one.
+pub trait RemoveSuffix<Suffix> {
+    type Output;
+
+    /// Fallible counterpart to [`Self::remove_suffix`]; returns [`RangeError::NotASuffix`] if
+    /// `suffix` doesn't end where `self` does, [`RangeError::SuffixOutOfBounds`] if it doesn't
+    /// start within `self`, or [`RangeError::UnboundedSuffixStart`] if `suffix` has no concrete
+    /// start bound, instead of panicking.
+    fn try_remove_suffix(self, suffix: Suffix) -> Result<Self::Output, RangeError>;
+
+    fn remove_suffix(self, suffix: Suffix) -> Self::Output
+    where
+        Self: Sized,
+    {
+        self.try_remove_suffix(suffix).unwrap()
+    }
+}
+
+impl<Self_, Suffix> RemoveSuffix<Suffix> for Self_
+where
+    Self_: IntoBounds + StartKind,
+    Suffix: IntoBounds<Item = Self_::Item>,
+    Self_::Item: PartialOrd,
+    Combiner: Combine<Self_::Item, Self_::Kind, ExcludedEnd>,
+    CombinedRange<Self_::Kind, ExcludedEnd, Self_::Item>: FromBounds<Self_::Item>,
+{
+    type Output = CombinedRange<Self_::Kind, ExcludedEnd, Self_::Item>;
+
+    fn try_remove_suffix(self, suffix: Suffix) -> Result<Self::Output, RangeError> {
+        let (start, self_end) = self.into_bounds();
+        let (suffix_start, suffix_end) = suffix.into_bounds();
+
+        if !bounds_eq(self_end.as_ref(), suffix_end.as_ref()) {
+            return Err(RangeError::NotASuffix);
+        }
+
+        let value = match suffix_start {
+            Bound::Included(value) => value,
+            _ => return Err(RangeError::UnboundedSuffixStart),
+        };
+
+        if !start_le_start(start.as_ref(), Bound::Included(&value))
+            || !end_le_end(Bound::Included(&value), self_end.as_ref())
+        {
+            return Err(RangeError::SuffixOutOfBounds);
+        }
+
+        Ok(Self::Output::from_bounds((start, Bound::Excluded(value))))
+    }
+}
@@ -0,0 +1,34 @@
+//! Bridges an inclusive bound to the exclusive bound immediately following it.
+//!
+//! Half-open boundaries (`Excluded`) already carry the value one past the last included
+//! element, so most of `RangeExt` never needs this. It only comes up when an operation has
+//! to turn someone's *inclusive* upper bound (`..=5`) into the start of whatever remains
+//! after it (which must begin at `6`, not `5`).
+
+/// A type whose values have a well-defined successor, letting an inclusive bound (`..=x`) be
+/// turned into the equivalent exclusive one (`..x.successor()`).
+pub trait Successor: Sized {
+    fn successor(self) -> Self;
+
+    /// Fallible counterpart to [`Self::successor`]; returns `None` instead of overflowing when
+    /// `self` is already the type's maximum representable value.
+    fn checked_successor(self) -> Option<Self>;
+}
+
+macro_rules! impl_successor_for_int {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Successor for $t {
+                fn successor(self) -> Self {
+                    self + 1
+                }
+
+                fn checked_successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_successor_for_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);